@@ -1,6 +1,6 @@
 //! A crate for deriving the [`Deref`](https://doc.rust-lang.org/std/ops/trait.Deref.html)
-//! and [`DerefMut`](https://doc.rust-lang.org/std/ops/trait.DerefMut.html) 
-//! traits from the standard library onto structs with at least one field. 
+//! and [`DerefMut`](https://doc.rust-lang.org/std/ops/trait.DerefMut.html)
+//! traits from the standard library onto structs with at least one field.
 //! Fields with references are passed directly.
 //!
 //! # Examples
@@ -14,7 +14,7 @@
 //!     #[target] inner: String,
 //!     count: usize,
 //! }
-//! 
+//!
 //!
 //! // When there is only one field, annotation is optional instead.
 //!
@@ -24,11 +24,67 @@
 //! #[derive(Deref, DerefMut)]
 //! struct CountWrapper(#[target] usize);
 //! ```
+//!
+//! # Forwarding
+//!
+//! `#[target(forward)]` chains through the field's own `Deref`/`DerefMut`
+//! implementation instead of stopping at the field's type, which is useful
+//! when the field is itself a smart pointer.
+//!
+//! ```rust
+//! use derived_deref::{Deref, DerefMut};
+//!
+//! #[derive(Deref, DerefMut)]
+//! struct BoxedStr(#[target(forward)] Box<str>);
+//! ```
+//!
+//! # `AsRef`/`AsMut`
+//!
+//! [`AsRef`](https://doc.rust-lang.org/std/convert/trait.AsRef.html) and
+//! [`AsMut`](https://doc.rust-lang.org/std/convert/trait.AsMut.html) can be
+//! derived using the same `#[target]` selection.
+//!
+//! ```rust
+//! use derived_deref::{AsRef, AsMut};
+//!
+//! #[derive(AsRef, AsMut)]
+//! struct StringWrapper(String);
+//! ```
+//!
+//! # `From`
+//!
+//! `From` can be derived for newtypes, building any other fields with
+//! [`Default`](https://doc.rust-lang.org/std/default/trait.Default.html).
+//!
+//! ```rust
+//! use derived_deref::From;
+//!
+//! #[derive(From)]
+//! struct StringWrapper(String);
+//!
+//! #[derive(From)]
+//! struct StringWithCount {
+//!     #[target] inner: String,
+//!     count: usize,
+//! }
+//! ```
+//!
+//! # `Index`/`IndexMut`
+//!
+//! `Index`/`IndexMut` forward to the target field, generic over any index
+//! type the field itself supports.
+//!
+//! ```rust
+//! use derived_deref::{Index, IndexMut};
+//!
+//! #[derive(Index, IndexMut)]
+//! struct Grid(Vec<usize>);
+//! ```
 
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, ItemStruct, Ident, Generics, Field, Fields, Index, Type, punctuated::Punctuated, token::Comma};
+use syn::{parse_macro_input, parse_quote, ItemStruct, Ident, Generics, Field, Fields, Index, Type, WhereClause, punctuated::Punctuated, token::Comma};
 use quote::quote;
 use proc_macro2::TokenStream as TokenStream2;
 
@@ -44,12 +100,12 @@ pub fn derive_deref(input: TokenStream) -> TokenStream {
     // ...to then get the desired field, one marked by `#[target]`.
     // However, if there's only one field, being marked is no longer required.
     match extract_field_parameters(item_struct.fields, "Deref") {
-        Ok((field_name, field_type, is_mut_reference)) => impl_deref(name, generics, field_name, Some(field_type), is_mut_reference),
+        Ok((field_name, field_type, is_mut_reference, is_forward)) => impl_deref(name, generics, field_name, field_type, true, is_mut_reference, is_forward),
         Err(error) => error,
     }
 }
 
-/// Derives the [`DerefMut`](https://doc.rust-lang.org/std/ops/trait.DerefMut.html) 
+/// Derives the [`DerefMut`](https://doc.rust-lang.org/std/ops/trait.DerefMut.html)
 /// trait, passing the field directly if a reference type. This will fail to
 /// compile if the chosen field is an immutable reference type.
 #[proc_macro_derive(DerefMut, attributes(target))]
@@ -61,7 +117,81 @@ pub fn derive_deref_mut(input: TokenStream) -> TokenStream {
     let generics = item_struct.generics;
 
     match extract_field_parameters(item_struct.fields, "DerefMut") {
-        Ok((field_name, _, is_mut_reference)) => impl_deref(name, generics, field_name, None, is_mut_reference),
+        Ok((field_name, field_type, is_mut_reference, is_forward)) => impl_deref(name, generics, field_name, field_type, false, is_mut_reference, is_forward),
+        Err(error) => error,
+    }
+}
+
+/// Derives the [`AsRef`](https://doc.rust-lang.org/std/convert/trait.AsRef.html)
+/// trait, passing the field directly if a reference type.
+#[proc_macro_derive(AsRef, attributes(target))]
+pub fn derive_as_ref(input: TokenStream) -> TokenStream {
+    let item_struct = parse_macro_input!(input as ItemStruct);
+    let name = item_struct.ident;
+    let generics = item_struct.generics;
+
+    match extract_field_parameters(item_struct.fields, "AsRef") {
+        Ok((field_name, field_type, is_mut_reference, _)) => impl_as_ref(name, generics, field_name, field_type, true, is_mut_reference),
+        Err(error) => error,
+    }
+}
+
+/// Derives the [`AsMut`](https://doc.rust-lang.org/std/convert/trait.AsMut.html)
+/// trait, passing the field directly if a reference type. This will fail to
+/// compile if the chosen field is an immutable reference type.
+#[proc_macro_derive(AsMut, attributes(target))]
+pub fn derive_as_mut(input: TokenStream) -> TokenStream {
+    let item_struct = parse_macro_input!(input as ItemStruct);
+    let name = item_struct.ident;
+    let generics = item_struct.generics;
+
+    match extract_field_parameters(item_struct.fields, "AsMut") {
+        Ok((field_name, field_type, is_mut_reference, _)) => impl_as_ref(name, generics, field_name, field_type, false, is_mut_reference),
+        Err(error) => error,
+    }
+}
+
+/// Derives the [`From`](https://doc.rust-lang.org/std/convert/trait.From.html)
+/// trait for the target field. Any other fields are built with
+/// [`Default`](https://doc.rust-lang.org/std/default/trait.Default.html).
+#[proc_macro_derive(From, attributes(target))]
+pub fn derive_from(input: TokenStream) -> TokenStream {
+    let item_struct = parse_macro_input!(input as ItemStruct);
+    let name = item_struct.ident;
+    let generics = item_struct.generics;
+
+    match extract_from_parameters(item_struct.fields, "From") {
+        Ok((field_type, constructor, default_bounds)) => impl_from(name, generics, field_type, constructor, default_bounds),
+        Err(error) => error,
+    }
+}
+
+/// Derives the [`Index`](https://doc.rust-lang.org/std/ops/trait.Index.html)
+/// trait, generic over any index type the target field itself supports.
+#[proc_macro_derive(Index, attributes(target))]
+pub fn derive_index(input: TokenStream) -> TokenStream {
+    let item_struct = parse_macro_input!(input as ItemStruct);
+    let name = item_struct.ident;
+    let generics = item_struct.generics;
+
+    match extract_field_parameters(item_struct.fields, "Index") {
+        Ok((field_name, field_type, is_mut_reference, _)) => impl_index(name, generics, field_name, field_type, true, is_mut_reference),
+        Err(error) => error,
+    }
+}
+
+/// Derives the [`IndexMut`](https://doc.rust-lang.org/std/ops/trait.IndexMut.html)
+/// trait, generic over any index type the target field itself supports.
+/// This will fail to compile if the chosen field is an immutable reference
+/// type.
+#[proc_macro_derive(IndexMut, attributes(target))]
+pub fn derive_index_mut(input: TokenStream) -> TokenStream {
+    let item_struct = parse_macro_input!(input as ItemStruct);
+    let name = item_struct.ident;
+    let generics = item_struct.generics;
+
+    match extract_field_parameters(item_struct.fields, "IndexMut") {
+        Ok((field_name, field_type, is_mut_reference, _)) => impl_index(name, generics, field_name, field_type, false, is_mut_reference),
         Err(error) => error,
     }
 }
@@ -70,21 +200,18 @@ pub fn derive_deref_mut(input: TokenStream) -> TokenStream {
 fn get_field(fields: Punctuated<Field, Comma>) -> Result<(usize, Field), TokenStream> {
     let attribute_name = "target";
     let error = || quote! { compile_error!("`#[target]` is required for one field"); }.into();
-    
+
     let has_one_field = fields.len() == 1;
     let mut fields_iter = fields.into_iter().fuse().enumerate();
-    
+
     if has_one_field {
         // An infallible call to take the first field.
         fields_iter.next().ok_or_else(error)
     } else {
-        // Below filters for the fields marked correctly with `#[target]`.
+        // Below filters for the fields marked correctly with `#[target]`,
+        // either bare or with arguments such as `#[target(forward)]`.
         let mut fields_iter = fields_iter.filter(|(_, field)| {
-            field.attrs.iter().any(|attribute| {
-                attribute.meta
-                    .require_path_only()
-                    .is_ok_and(|path| path.is_ident(attribute_name))
-            })
+            field.attrs.iter().any(|attribute| attribute.path().is_ident(attribute_name))
         });
 
         // Takes the next element, only keeping the `Some` if the next take
@@ -98,27 +225,41 @@ fn get_field(fields: Punctuated<Field, Comma>) -> Result<(usize, Field), TokenSt
     }
 }
 
-fn extract_field_parameters(fields: Fields, trait_name: &str) -> Result<(TokenStream2, Type, Option<bool>), TokenStream> {
+// Checks whether the selected field's `#[target]` attribute requests
+// forward mode, i.e. `#[target(forward)]`, chaining through the field's own
+// `Deref`/`DerefMut` implementation instead of stopping at its type.
+fn is_forward(field: &Field) -> bool {
+    field.attrs.iter().any(|attribute| {
+        attribute.path().is_ident("target")
+            && attribute
+                .parse_args::<Ident>()
+                .is_ok_and(|ident| ident == "forward")
+    })
+}
+
+fn extract_field_parameters(fields: Fields, trait_name: &str) -> Result<(TokenStream2, Type, Option<bool>, bool), TokenStream> {
     match fields {
         Fields::Named(fields) => {
             let (_, field) = get_field(fields.named)?;
+            let forward = is_forward(&field);
             let field_name = field.ident.unwrap();
             let (field_type, is_mut_reference) = match field.ty {
                 Type::Reference(reference_type) => (*reference_type.elem, Some(reference_type.mutability.is_some())),
                 field_type => (field_type, None),
             };
 
-            Ok((quote! { #field_name }, field_type, is_mut_reference))
+            Ok((quote! { #field_name }, field_type, is_mut_reference, forward))
         },
         Fields::Unnamed(fields) => {
             let (field_index, field) = get_field(fields.unnamed)?;
+            let forward = is_forward(&field);
             let field_index = Index::from(field_index);
             let (field_type, is_mut_reference) = match field.ty {
                 Type::Reference(reference_type) => (*reference_type.elem, Some(reference_type.mutability.is_some())),
                 field_type => (field_type, None),
             };
 
-            Ok((quote! { #field_index }, field_type, is_mut_reference))
+            Ok((quote! { #field_index }, field_type, is_mut_reference, forward))
         },
         Fields::Unit => {
             let error = &format!("unable to implement `{}` trait for struct of no fields", trait_name)[..];
@@ -128,25 +269,102 @@ fn extract_field_parameters(fields: Fields, trait_name: &str) -> Result<(TokenSt
     }
 }
 
+// Like `extract_field_parameters`, but for `From`: besides the target
+// field's type, it also produces the constructor for the whole struct
+// (defaulting every other field) along with the types those other fields
+// need to satisfy `Default` for.
+fn extract_from_parameters(fields: Fields, trait_name: &str) -> Result<(Type, TokenStream2, Vec<Type>), TokenStream> {
+    match fields {
+        Fields::Named(fields) => {
+            let fields = fields.named;
+            let (target_index, _) = get_field(fields.clone())?;
+
+            let mut field_type = None;
+            let mut default_bounds = Vec::new();
+            let inits = fields.into_iter().enumerate().map(|(index, field)| {
+                let field_name = field.ident.unwrap();
+
+                if index == target_index {
+                    field_type = Some(field.ty);
+                    quote! { #field_name: value }
+                } else {
+                    default_bounds.push(field.ty);
+                    quote! { #field_name: Default::default() }
+                }
+            }).collect::<Vec<_>>();
+
+            Ok((field_type.unwrap(), quote! { Self { #(#inits),* } }, default_bounds))
+        },
+        Fields::Unnamed(fields) => {
+            let fields = fields.unnamed;
+            let (target_index, _) = get_field(fields.clone())?;
+
+            let mut field_type = None;
+            let mut default_bounds = Vec::new();
+            let inits = fields.into_iter().enumerate().map(|(index, field)| {
+                if index == target_index {
+                    field_type = Some(field.ty);
+                    quote! { value }
+                } else {
+                    default_bounds.push(field.ty);
+                    quote! { Default::default() }
+                }
+            }).collect::<Vec<_>>();
+
+            Ok((field_type.unwrap(), quote! { Self(#(#inits),*) }, default_bounds))
+        },
+        Fields::Unit => {
+            let error = &format!("unable to implement `{}` trait for struct of no fields", trait_name)[..];
+
+            Err(quote! { compile_error!(#error); }.into())
+        }
+    }
+}
+
+// Appends an extra `Type: Trait` bound onto the struct's existing where
+// clause, if any.
+fn append_where_bound(where_clause: Option<&WhereClause>, bound: TokenStream2) -> WhereClause {
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    where_clause.predicates.push(parse_quote!(#bound));
+    where_clause
+}
+
 fn impl_deref(
     struct_name: Ident,
     struct_generics: Generics,
     field_name: TokenStream2,
-    // Only whenever there is no need for `field_type` does it mean `Deref` is 
-    // being implemented with its mutable counterpart.
-    field_type: Option<Type>,
+    field_type: Type,
+    // `true` when implementing `Deref`, `false` when implementing `DerefMut`.
+    is_deref: bool,
     // For if the field is a reference: `Some` if it is and `None` otherwise.
     // The boolean is `true` when it is mutable and `false` otherwise.
     is_mut_reference: Option<bool>,
-) -> TokenStream 
+    // Whether `#[target(forward)]` was used, chaining through the field's own
+    // `Deref`/`DerefMut` implementation instead of stopping at its type.
+    is_forward: bool,
+) -> TokenStream
 {
     let (impl_generics, type_generics, where_clause) = struct_generics.split_for_impl();
 
-    match field_type {
-        Some(field_type) => {
-            // If not a reference, "&" is passed. If it is, nothing is instead. 
+    if is_deref {
+        if is_forward {
+            // If not a reference, "&" is passed. If it is, nothing is instead.
+            let reference = is_mut_reference.map_or_else(|| Some(quote!(&)), |_| None);
+            let where_clause = append_where_bound(where_clause, quote! { #field_type: core::ops::Deref });
+
+            quote! {
+                impl #impl_generics core::ops::Deref for #struct_name #type_generics #where_clause {
+                    type Target = <#field_type as core::ops::Deref>::Target;
+
+                    fn deref(&self) -> &Self::Target {
+                        core::ops::Deref::deref(#reference self.#field_name)
+                    }
+                }
+            }
+        } else {
+            // If not a reference, "&" is passed. If it is, nothing is instead.
             let reference = is_mut_reference.map_or_else(|| Some(quote!(&)), |_| None);
-            
+
             quote! {
                 impl #impl_generics core::ops::Deref for #struct_name #type_generics #where_clause {
                     type Target = #field_type;
@@ -156,14 +374,26 @@ fn impl_deref(
                     }
                 }
             }
-        },
-        None => {
-            let reference = match is_mut_reference {
-                Some(true) => None,
-                Some(false) => return quote! { compile_error!("`#[target]` is unable to be of an immutable reference"); }.into(),
-                None => Some(quote!(&mut)),
-            };
-            
+        }
+    } else {
+        if let Some(false) = is_mut_reference {
+            return quote! { compile_error!("`#[target]` is unable to be of an immutable reference"); }.into();
+        }
+
+        if is_forward {
+            let reference = is_mut_reference.map_or_else(|| Some(quote!(&mut)), |_| None);
+            let where_clause = append_where_bound(where_clause, quote! { #field_type: core::ops::DerefMut });
+
+            quote! {
+                impl #impl_generics core::ops::DerefMut for #struct_name #type_generics #where_clause {
+                    fn deref_mut(&mut self) -> &mut Self::Target {
+                        core::ops::DerefMut::deref_mut(#reference self.#field_name)
+                    }
+                }
+            }
+        } else {
+            let reference = is_mut_reference.map_or_else(|| Some(quote!(&mut)), |_| None);
+
             quote! {
                 impl #impl_generics core::ops::DerefMut for #struct_name #type_generics #where_clause {
                     fn deref_mut(&mut self) -> &mut Self::Target {
@@ -171,7 +401,128 @@ fn impl_deref(
                     }
                 }
             }
-        },
+        }
+    }
+    .into()
+}
+
+fn impl_as_ref(
+    struct_name: Ident,
+    struct_generics: Generics,
+    field_name: TokenStream2,
+    field_type: Type,
+    // `true` when implementing `AsRef`, `false` when implementing `AsMut`.
+    is_as_ref: bool,
+    // For if the field is a reference: `Some` if it is and `None` otherwise.
+    // The boolean is `true` when it is mutable and `false` otherwise.
+    is_mut_reference: Option<bool>,
+) -> TokenStream
+{
+    let (impl_generics, type_generics, where_clause) = struct_generics.split_for_impl();
+
+    if is_as_ref {
+        // If not a reference, "&" is passed. If it is, nothing is instead.
+        let reference = is_mut_reference.map_or_else(|| Some(quote!(&)), |_| None);
+
+        quote! {
+            impl #impl_generics AsRef<#field_type> for #struct_name #type_generics #where_clause {
+                fn as_ref(&self) -> &#field_type {
+                    #reference self.#field_name
+                }
+            }
+        }
+    } else {
+        if let Some(false) = is_mut_reference {
+            return quote! { compile_error!("`#[target]` is unable to be of an immutable reference"); }.into();
+        }
+
+        let reference = is_mut_reference.map_or_else(|| Some(quote!(&mut)), |_| None);
+
+        quote! {
+            impl #impl_generics AsMut<#field_type> for #struct_name #type_generics #where_clause {
+                fn as_mut(&mut self) -> &mut #field_type {
+                    #reference self.#field_name
+                }
+            }
+        }
+    }
+    .into()
+}
+
+fn impl_from(
+    struct_name: Ident,
+    struct_generics: Generics,
+    field_type: Type,
+    constructor: TokenStream2,
+    // The types of the non-target fields, which must be `Default` so the
+    // constructor can fill them in.
+    default_bounds: Vec<Type>,
+) -> TokenStream
+{
+    let (impl_generics, type_generics, where_clause) = struct_generics.split_for_impl();
+
+    let where_clause = default_bounds.into_iter().fold(where_clause.cloned(), |where_clause, bound| {
+        Some(append_where_bound(where_clause.as_ref(), quote! { #bound: Default }))
+    });
+
+    quote! {
+        impl #impl_generics From<#field_type> for #struct_name #type_generics #where_clause {
+            fn from(value: #field_type) -> Self {
+                #constructor
+            }
+        }
+    }
+    .into()
+}
+
+fn impl_index(
+    struct_name: Ident,
+    struct_generics: Generics,
+    field_name: TokenStream2,
+    field_type: Type,
+    // `true` when implementing `Index`, `false` when implementing `IndexMut`.
+    is_index: bool,
+    // For if the field is a reference: `Some` if it is and `None` otherwise.
+    // The boolean is `true` when it is mutable and `false` otherwise.
+    is_mut_reference: Option<bool>,
+) -> TokenStream
+{
+    // `__Idx` is generic over whatever index type the field itself supports,
+    // so it's appended onto the struct's own generics for the `impl` header.
+    let mut generics_with_index = struct_generics.clone();
+    generics_with_index.params.push(parse_quote!(__Idx));
+    let (impl_generics, _, _) = generics_with_index.split_for_impl();
+    let (_, type_generics, where_clause) = struct_generics.split_for_impl();
+
+    if is_index {
+        // If not a reference, "&" is passed. If it is, nothing is instead.
+        let reference = is_mut_reference.map_or_else(|| Some(quote!(&)), |_| None);
+        let where_clause = append_where_bound(where_clause, quote! { #field_type: core::ops::Index<__Idx> });
+
+        quote! {
+            impl #impl_generics core::ops::Index<__Idx> for #struct_name #type_generics #where_clause {
+                type Output = <#field_type as core::ops::Index<__Idx>>::Output;
+
+                fn index(&self, index: __Idx) -> &Self::Output {
+                    core::ops::Index::index(#reference self.#field_name, index)
+                }
+            }
+        }
+    } else {
+        if let Some(false) = is_mut_reference {
+            return quote! { compile_error!("`#[target]` is unable to be of an immutable reference"); }.into();
+        }
+
+        let reference = is_mut_reference.map_or_else(|| Some(quote!(&mut)), |_| None);
+        let where_clause = append_where_bound(where_clause, quote! { #field_type: core::ops::IndexMut<__Idx> });
+
+        quote! {
+            impl #impl_generics core::ops::IndexMut<__Idx> for #struct_name #type_generics #where_clause {
+                fn index_mut(&mut self, index: __Idx) -> &mut Self::Output {
+                    core::ops::IndexMut::index_mut(#reference self.#field_name, index)
+                }
+            }
+        }
     }
     .into()
 }